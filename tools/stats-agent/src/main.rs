@@ -6,15 +6,21 @@
 // ============================================
 
 use lazy_static::lazy_static;
+use regex::Regex;
 use serde::Serialize;
 use std::{
+    collections::HashMap,
     env,
-    io::{self, Write},
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
     sync::{Arc, Mutex},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
+};
+use sysinfo::{
+    Components, CpuRefreshKind, Disks, MemoryRefreshKind, Networks, Pid, ProcessRefreshKind,
+    Signal, System,
 };
-use sysinfo::{CpuRefreshKind, Disks, MemoryRefreshKind, Networks, Pid, ProcessRefreshKind, System};
 
 // ============================================
 // JSON Structures
@@ -29,6 +35,10 @@ struct Stats {
     network: Vec<NetStats>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     processes: Vec<ProcessStats>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    components: Vec<ComponentStats>,
+    load: LoadStats,
+    uptime_secs: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     os: Option<OsInfo>,
 }
@@ -46,6 +56,8 @@ struct DiskStats {
     name: String,
     available: u64,
     total: u64,
+    read_bytes_per_sec: f64,
+    written_bytes_per_sec: f64,
 }
 
 #[derive(Serialize)]
@@ -57,6 +69,12 @@ struct NetStats {
     tx_packets: u64,
     rx_errors: u64,
     tx_errors: u64,
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
+    rx_packets_per_sec: f64,
+    tx_packets_per_sec: f64,
+    rx_errors_per_sec: f64,
+    tx_errors_per_sec: f64,
 }
 
 #[derive(Serialize)]
@@ -65,7 +83,89 @@ struct ProcessStats {
     name: String,
     exe: String,
     memory: u64,
+    // Raw sysinfo reading: percent summed across cores, may exceed 100.
     cpu: f32,
+    // Same usage divided by core count, for a 0-100 whole-machine scale.
+    cpu_normalized: f32,
+}
+
+#[derive(Serialize)]
+struct LoadStats {
+    // null on platforms (e.g. Windows) without a load-average concept.
+    one: Option<f64>,
+    five: Option<f64>,
+    fifteen: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct ComponentStats {
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    critical: Option<f32>,
+}
+
+// ============================================
+// Process Query Options
+// ============================================
+
+// Which metric to rank processes by before truncating to `top`.
+enum SortKey {
+    Cpu,
+    Memory,
+    Pid,
+}
+
+impl SortKey {
+    fn parse(value: &str) -> Self {
+        match value {
+            "memory" => SortKey::Memory,
+            "pid" => SortKey::Pid,
+            _ => SortKey::Cpu,
+        }
+    }
+}
+
+// Name matcher. Compiled as a regex when possible, falling back to a plain
+// substring match so a malformed pattern filters rather than aborting the run.
+enum ProcessFilter {
+    Regex(Regex),
+    Substring(String),
+}
+
+impl ProcessFilter {
+    fn compile(pattern: &str) -> Self {
+        match Regex::new(pattern) {
+            Ok(re) => ProcessFilter::Regex(re),
+            Err(_) => ProcessFilter::Substring(pattern.to_string()),
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            ProcessFilter::Regex(re) => re.is_match(name),
+            ProcessFilter::Substring(sub) => name.contains(sub),
+        }
+    }
+}
+
+struct ProcessOptions {
+    filter: Option<ProcessFilter>,
+    sort: SortKey,
+    top: usize,
+}
+
+impl Default for ProcessOptions {
+    fn default() -> Self {
+        ProcessOptions {
+            filter: None,
+            sort: SortKey::Cpu,
+            top: 10,
+        }
+    }
 }
 
 #[derive(Serialize, Clone)]
@@ -82,6 +182,81 @@ struct OsInfo {
 
 lazy_static! {
     static ref SYS: Arc<Mutex<System>> = Arc::new(Mutex::new(System::new()));
+    // Previous cumulative samples used to derive per-second rates. Keyed by
+    // interface/disk name and timestamped with the last refresh so the elapsed
+    // window follows the actual call cadence (daemon interval, 500ms warm-up).
+    static ref RATES: Arc<Mutex<RateState>> = Arc::new(Mutex::new(RateState::default()));
+}
+
+#[derive(Default)]
+struct RateState {
+    last: Option<Instant>,
+    net: HashMap<String, NetCounters>,
+    disk: HashMap<String, DiskCounters>,
+}
+
+#[derive(Clone, Copy)]
+struct NetCounters {
+    rx_bytes: u64,
+    tx_bytes: u64,
+    rx_packets: u64,
+    tx_packets: u64,
+    rx_errors: u64,
+    tx_errors: u64,
+}
+
+#[derive(Clone, Copy)]
+struct DiskCounters {
+    read_bytes: u64,
+    written_bytes: u64,
+}
+
+// Sample current net/disk counters into RATES and stamp the moment. The
+// one-shot paths call this before their 500ms warm-up sleep so the subsequent
+// collect_stats sees a prior sample and a real elapsed window.
+fn seed_rate_baseline() {
+    let mut rates = RATES.lock().unwrap();
+
+    let disks = Disks::new_with_refreshed_list();
+    for d in disks.list() {
+        let usage = d.usage();
+        rates.disk.insert(
+            d.name().to_string_lossy().into_owned(),
+            DiskCounters {
+                read_bytes: usage.total_read_bytes,
+                written_bytes: usage.total_written_bytes,
+            },
+        );
+    }
+
+    let mut networks = Networks::new_with_refreshed_list();
+    networks.refresh(true);
+    for (name, n) in networks.iter() {
+        rates.net.insert(
+            name.clone(),
+            NetCounters {
+                rx_bytes: n.total_received(),
+                tx_bytes: n.total_transmitted(),
+                rx_packets: n.total_packets_received(),
+                tx_packets: n.total_packets_transmitted(),
+                rx_errors: n.total_errors_on_received(),
+                tx_errors: n.total_errors_on_transmitted(),
+            },
+        );
+    }
+
+    rates.last = Some(Instant::now());
+}
+
+// Per-second rate between two cumulative readings. Returns 0 when there is no
+// prior sample, no elapsed window, or the counter went backwards (reset).
+fn per_sec(current: u64, prev: Option<u64>, elapsed: Option<f64>) -> f64 {
+    match (prev, elapsed) {
+        (Some(prev), Some(elapsed)) if elapsed > 0.0 && current >= prev => {
+            (current - prev) as f64 / elapsed
+        }
+        _ => 0.0,
+    }
 }
 
 // ============================================
@@ -98,8 +273,20 @@ fn main() {
                 let interval = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(5);
                 daemon_mode(interval);
             }
-            "json" => json_once(false),
-            "json-processes" => json_once(true),
+            "json" => json_once(None),
+            "json-processes" => json_once(Some(parse_process_options(&args[2..]))),
+            "serve" => {
+                // Optional port (default 8080)
+                let port = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(8080);
+                serve_mode(port);
+            }
+            "kill" => match args.get(2).and_then(|s| s.parse().ok()) {
+                Some(pid) => kill_process(pid, args.get(3).map(|s| s.as_str())),
+                None => {
+                    println!("{{\"error\":\"missing or invalid pid\"}}");
+                    std::process::exit(1);
+                }
+            },
             "version" => {
                 println!("stats-agent v{}", env!("CARGO_PKG_VERSION"));
             }
@@ -107,10 +294,42 @@ fn main() {
         }
     } else {
         // Default: single JSON output
-        json_once(false);
+        json_once(None);
     }
 }
 
+// Parse `json-processes` flags: --filter <pattern>, --sort <cpu|memory|pid>,
+// --top <N>. Unknown flags and missing values fall back to the defaults.
+fn parse_process_options(args: &[String]) -> ProcessOptions {
+    let mut opts = ProcessOptions::default();
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--filter" => {
+                if let Some(pattern) = args.get(i + 1) {
+                    opts.filter = Some(ProcessFilter::compile(pattern));
+                    i += 1;
+                }
+            }
+            "--sort" => {
+                if let Some(key) = args.get(i + 1) {
+                    opts.sort = SortKey::parse(key);
+                    i += 1;
+                }
+            }
+            "--top" => {
+                if let Some(n) = args.get(i + 1).and_then(|s| s.parse().ok()) {
+                    opts.top = n;
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    opts
+}
+
 fn print_usage() {
     eprintln!("Usage: stats-agent [command]");
     eprintln!();
@@ -118,6 +337,9 @@ fn print_usage() {
     eprintln!("  daemon [interval]  Run as daemon, output JSON every N seconds (default: 5)");
     eprintln!("  json               Output stats once as JSON");
     eprintln!("  json-processes     Output stats with top processes");
+    eprintln!("                       [--filter <pattern>] [--sort cpu|memory|pid] [--top N]");
+    eprintln!("  serve [port]       Serve stats over HTTP (default port: 8080)");
+    eprintln!("  kill <pid> [sig]   Terminate a process by PID (default signal: KILL)");
     eprintln!("  version            Show version");
     eprintln!();
     eprintln!("Without arguments, outputs stats once as JSON.");
@@ -144,7 +366,7 @@ fn daemon_mode(interval_secs: u64) {
     let mut first = true;
 
     loop {
-        let stats = collect_stats(if first { os_info.clone() } else { None }, false);
+        let stats = collect_stats(if first { os_info.clone() } else { None }, None);
         first = false;
 
         match serde_json::to_string(&stats) {
@@ -161,15 +383,186 @@ fn daemon_mode(interval_secs: u64) {
     }
 }
 
+// ============================================
+// Serve Mode - HTTP endpoint
+// ============================================
+
+fn serve_mode(port: u16) {
+    let listener = match TcpListener::bind(("0.0.0.0", port)) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("Error binding to port {}: {}", port, e);
+            std::process::exit(1);
+        }
+    };
+
+    // Warm up the shared SYS instance so the first request has accurate
+    // CPU deltas instead of a cold 0% reading.
+    {
+        let mut sys = SYS.lock().unwrap();
+        sys.refresh_cpu_specifics(CpuRefreshKind::everything());
+    }
+    seed_rate_baseline();
+    thread::sleep(Duration::from_millis(500));
+
+    eprintln!("stats-agent serving on http://0.0.0.0:{}/stats", port);
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => handle_connection(stream),
+            Err(e) => eprintln!("Connection error: {}", e),
+        }
+    }
+}
+
+fn handle_connection(mut stream: TcpStream) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+
+    // Request line looks like: "GET /stats/processes?top=5 HTTP/1.1"
+    let target = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let (path, query) = match target.split_once('?') {
+        Some((p, q)) => (p, q),
+        None => (target, ""),
+    };
+
+    // `?processes=true` opts /stats into the process list; /stats/processes
+    // always includes it.
+    let processes_param = query_flag(query, "processes");
+
+    let (status, body) = match path {
+        "/stats" => {
+            let proc_opts = processes_param.then(|| process_options_from_query(query));
+            let stats = collect_stats(get_os_info(), proc_opts);
+            json_response(&stats)
+        }
+        "/stats/processes" => {
+            let stats = collect_stats(get_os_info(), Some(process_options_from_query(query)));
+            json_response(&stats)
+        }
+        _ => (
+            "404 Not Found",
+            "{\"error\":\"not found\"}".to_string(),
+        ),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+    let _ = stream.flush();
+}
+
+fn query_flag(query: &str, key: &str) -> bool {
+    query
+        .split('&')
+        .filter_map(|kv| kv.split_once('='))
+        .any(|(k, v)| k == key && v == "true")
+}
+
+fn query_value<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|kv| kv.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v)
+}
+
+// Map the `?filter=`/`?sort=`/`?top=` query params onto process options.
+fn process_options_from_query(query: &str) -> ProcessOptions {
+    let mut opts = ProcessOptions::default();
+    if let Some(pattern) = query_value(query, "filter") {
+        opts.filter = Some(ProcessFilter::compile(pattern));
+    }
+    if let Some(sort) = query_value(query, "sort") {
+        opts.sort = SortKey::parse(sort);
+    }
+    if let Some(top) = query_value(query, "top").and_then(|v| v.parse().ok()) {
+        opts.top = top;
+    }
+    opts
+}
+
+fn json_response<T: Serialize>(value: &T) -> (&'static str, String) {
+    match serde_json::to_string(value) {
+        Ok(json) => ("200 OK", json),
+        Err(e) => (
+            "500 Internal Server Error",
+            format!("{{\"error\":\"{}\"}}", e),
+        ),
+    }
+}
+
+// ============================================
+// Kill Mode - Terminate a process by PID
+// ============================================
+
+#[derive(Serialize)]
+struct KillResult {
+    pid: u32,
+    killed: bool,
+}
+
+// Map a signal name (e.g. "TERM", "SIGKILL", "HUP") to a sysinfo signal.
+// Returns None for an unrecognized name so the caller can report it.
+fn parse_signal(name: &str) -> Option<Signal> {
+    match name.trim_start_matches("SIG").to_uppercase().as_str() {
+        "KILL" => Some(Signal::Kill),
+        "TERM" => Some(Signal::Term),
+        "INT" => Some(Signal::Interrupt),
+        "HUP" => Some(Signal::Hangup),
+        "QUIT" => Some(Signal::Quit),
+        _ => None,
+    }
+}
+
+fn kill_process(pid: u32, signal: Option<&str>) {
+    let mut sys = System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    let process = match sys.process(Pid::from_u32(pid)) {
+        Some(p) => p,
+        None => {
+            println!("{{\"error\":\"no process with pid {}\"}}", pid);
+            std::process::exit(1);
+        }
+    };
+
+    // An explicit signal routes through kill_with; the default is a plain kill
+    // (SIGKILL). kill_with yields None when the platform lacks the signal.
+    let killed = match signal {
+        Some(name) => match parse_signal(name) {
+            Some(sig) => process.kill_with(sig).unwrap_or(false),
+            None => {
+                println!("{{\"error\":\"unknown signal {}\"}}", name);
+                std::process::exit(1);
+            }
+        },
+        None => process.kill(),
+    };
+
+    let result = KillResult { pid, killed };
+    println!("{}", serde_json::to_string(&result).unwrap());
+    if !killed {
+        std::process::exit(1);
+    }
+}
+
 // ============================================
 // Stats Collection
 // ============================================
 
-fn collect_stats(os_info: Option<OsInfo>, include_processes: bool) -> Stats {
+fn collect_stats(os_info: Option<OsInfo>, proc_opts: Option<ProcessOptions>) -> Stats {
     let mut sys = SYS.lock().unwrap();
 
     // Refresh data
-    sys.refresh_memory_specifics(MemoryRefreshKind::new().with_ram().with_swap());
+    sys.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram().with_swap());
     sys.refresh_cpu_specifics(CpuRefreshKind::everything());
 
     // CPU per core
@@ -193,31 +586,46 @@ fn collect_stats(os_info: Option<OsInfo>, include_processes: bool) -> Stats {
         swap_used: sys.used_swap(),
     };
 
-    // Processes (top 10 by CPU)
-    let processes = if include_processes {
+    // Processes (ranked and truncated per the supplied options)
+    let processes = if let Some(opts) = proc_opts {
         sys.refresh_processes_specifics(
             sysinfo::ProcessesToUpdate::All,
             true,
-            ProcessRefreshKind::new().with_cpu().with_memory(),
+            ProcessRefreshKind::nothing().with_cpu().with_memory(),
         );
 
+        let cpu_count = sys.cpus().len().max(1) as f32;
         let mut procs: Vec<_> = sys
             .processes()
             .iter()
-            .map(|(pid, p)| ProcessStats {
-                pid: pid.as_u32(),
-                name: p.name().to_string_lossy().into_owned(),
-                exe: p
-                    .exe()
-                    .map(|e| e.to_string_lossy().into_owned())
-                    .unwrap_or_default(),
-                memory: p.memory(),
-                cpu: (p.cpu_usage() * 100.0).round() / 100.0,
+            .filter(|(_, p)| match &opts.filter {
+                Some(filter) => filter.matches(&p.name().to_string_lossy()),
+                None => true,
+            })
+            .map(|(pid, p)| {
+                let cpu = (p.cpu_usage() * 100.0).round() / 100.0;
+                ProcessStats {
+                    pid: pid.as_u32(),
+                    name: p.name().to_string_lossy().into_owned(),
+                    exe: p
+                        .exe()
+                        .map(|e| e.to_string_lossy().into_owned())
+                        .unwrap_or_default(),
+                    memory: p.memory(),
+                    cpu,
+                    cpu_normalized: (cpu / cpu_count * 100.0).round() / 100.0,
+                }
             })
             .collect();
 
-        procs.sort_by(|a, b| b.cpu.partial_cmp(&a.cpu).unwrap_or(std::cmp::Ordering::Equal));
-        procs.truncate(10);
+        match opts.sort {
+            SortKey::Cpu => {
+                procs.sort_by(|a, b| b.cpu.partial_cmp(&a.cpu).unwrap_or(std::cmp::Ordering::Equal))
+            }
+            SortKey::Memory => procs.sort_by_key(|p| std::cmp::Reverse(p.memory)),
+            SortKey::Pid => procs.sort_by_key(|p| p.pid),
+        }
+        procs.truncate(opts.top);
         procs
     } else {
         vec![]
@@ -226,34 +634,134 @@ fn collect_stats(os_info: Option<OsInfo>, include_processes: bool) -> Stats {
     // Release lock before slower operations
     drop(sys);
 
+    // Rate bookkeeping: elapsed since the previous sample (the last
+    // collect_stats call or seed_rate_baseline, whichever came later).
+    let now = Instant::now();
+    let mut rates = RATES.lock().unwrap();
+    let elapsed = rates.last.map(|l| now.duration_since(l).as_secs_f64());
+
     // Disks
     let disks_info = Disks::new_with_refreshed_list();
     let disks: Vec<DiskStats> = disks_info
         .list()
         .iter()
         .filter(|d| d.total_space() > 0)
-        .map(|d| DiskStats {
-            name: d.name().to_string_lossy().into_owned(),
-            available: d.available_space(),
-            total: d.total_space(),
+        .map(|d| {
+            let name = d.name().to_string_lossy().into_owned();
+            let usage = d.usage();
+            let prev = rates.disk.get(&name).copied();
+            let stats = DiskStats {
+                read_bytes_per_sec: per_sec(
+                    usage.total_read_bytes,
+                    prev.map(|p| p.read_bytes),
+                    elapsed,
+                ),
+                written_bytes_per_sec: per_sec(
+                    usage.total_written_bytes,
+                    prev.map(|p| p.written_bytes),
+                    elapsed,
+                ),
+                name: name.clone(),
+                available: d.available_space(),
+                total: d.total_space(),
+            };
+            rates.disk.insert(
+                name,
+                DiskCounters {
+                    read_bytes: usage.total_read_bytes,
+                    written_bytes: usage.total_written_bytes,
+                },
+            );
+            stats
         })
         .collect();
 
     // Network
     let mut networks = Networks::new_with_refreshed_list();
-    networks.refresh();
+    networks.refresh(true);
 
     let network: Vec<NetStats> = networks
         .iter()
         .filter(|(_, n)| n.total_received() > 0 || n.total_transmitted() > 0)
-        .map(|(name, n)| NetStats {
-            interface: name.clone(),
-            rx_bytes: n.total_received(),
-            tx_bytes: n.total_transmitted(),
-            rx_packets: n.total_packets_received(),
-            tx_packets: n.total_packets_transmitted(),
-            rx_errors: n.total_errors_on_received(),
-            tx_errors: n.total_errors_on_transmitted(),
+        .map(|(name, n)| {
+            let prev = rates.net.get(name).copied();
+            let stats = NetStats {
+                interface: name.clone(),
+                rx_bytes: n.total_received(),
+                tx_bytes: n.total_transmitted(),
+                rx_packets: n.total_packets_received(),
+                tx_packets: n.total_packets_transmitted(),
+                rx_errors: n.total_errors_on_received(),
+                tx_errors: n.total_errors_on_transmitted(),
+                rx_bytes_per_sec: per_sec(n.total_received(), prev.map(|p| p.rx_bytes), elapsed),
+                tx_bytes_per_sec: per_sec(n.total_transmitted(), prev.map(|p| p.tx_bytes), elapsed),
+                rx_packets_per_sec: per_sec(
+                    n.total_packets_received(),
+                    prev.map(|p| p.rx_packets),
+                    elapsed,
+                ),
+                tx_packets_per_sec: per_sec(
+                    n.total_packets_transmitted(),
+                    prev.map(|p| p.tx_packets),
+                    elapsed,
+                ),
+                rx_errors_per_sec: per_sec(
+                    n.total_errors_on_received(),
+                    prev.map(|p| p.rx_errors),
+                    elapsed,
+                ),
+                tx_errors_per_sec: per_sec(
+                    n.total_errors_on_transmitted(),
+                    prev.map(|p| p.tx_errors),
+                    elapsed,
+                ),
+            };
+            rates.net.insert(
+                name.clone(),
+                NetCounters {
+                    rx_bytes: n.total_received(),
+                    tx_bytes: n.total_transmitted(),
+                    rx_packets: n.total_packets_received(),
+                    tx_packets: n.total_packets_transmitted(),
+                    rx_errors: n.total_errors_on_received(),
+                    tx_errors: n.total_errors_on_transmitted(),
+                },
+            );
+            stats
+        })
+        .collect();
+
+    rates.last = Some(now);
+    drop(rates);
+
+    // Load average and uptime. Load average is unavailable on Windows, where
+    // sysinfo reports zeros; emit nulls there so the schema stays consistent.
+    let uptime_secs = System::uptime();
+    let load = if cfg!(target_os = "windows") {
+        LoadStats {
+            one: None,
+            five: None,
+            fifteen: None,
+        }
+    } else {
+        let avg = System::load_average();
+        LoadStats {
+            one: Some(avg.one),
+            five: Some(avg.five),
+            fifteen: Some(avg.fifteen),
+        }
+    };
+
+    // Components (thermal sensors). Empty on platforms without sensors.
+    let components_info = Components::new_with_refreshed_list();
+    let components: Vec<ComponentStats> = components_info
+        .list()
+        .iter()
+        .map(|c| ComponentStats {
+            label: c.label().to_string(),
+            temperature: c.temperature(),
+            max: c.max(),
+            critical: c.critical(),
         })
         .collect();
 
@@ -264,6 +772,9 @@ fn collect_stats(os_info: Option<OsInfo>, include_processes: bool) -> Stats {
         disks,
         network,
         processes,
+        components,
+        load,
+        uptime_secs,
         os: os_info,
     }
 }
@@ -286,15 +797,17 @@ fn get_os_info() -> Option<OsInfo> {
 // Single JSON Output
 // ============================================
 
-fn json_once(include_processes: bool) {
-    // First CPU read
+fn json_once(proc_opts: Option<ProcessOptions>) {
+    // First CPU read, plus a net/disk baseline so this single sample reports
+    // throughput rates over the fixed 500ms warm-up window below rather than 0.
     {
         let mut sys = SYS.lock().unwrap();
         sys.refresh_cpu_specifics(CpuRefreshKind::everything());
     }
+    seed_rate_baseline();
     thread::sleep(Duration::from_millis(500));
 
-    let stats = collect_stats(get_os_info(), include_processes);
+    let stats = collect_stats(get_os_info(), proc_opts);
 
     match serde_json::to_string_pretty(&stats) {
         Ok(json) => println!("{}", json),